@@ -0,0 +1,33 @@
+// Copyright 2018 the SVG Types Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::fmt;
+
+/// List of all errors.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Error {
+    /// An invalid number.
+    InvalidNumber(usize),
+    /// An invalid number count in a bounded number list.
+    InvalidNumberCount(usize),
+    /// Unexpected data at the end of a value.
+    UnexpectedData(usize),
+    /// Stream ended earlier than expected.
+    UnexpectedEndOfStream,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidNumber(pos) => write!(f, "invalid number at position {}", pos),
+            Error::InvalidNumberCount(pos) => {
+                write!(f, "invalid number count at position {}", pos)
+            }
+            Error::UnexpectedData(pos) => write!(f, "unexpected data at position {}", pos),
+            Error::UnexpectedEndOfStream => write!(f, "unexpected end of stream"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,168 @@
+// Copyright 2024 the SVG Types Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::{ByteExt, Error, Stream};
+
+impl<'a> Stream<'a> {
+    /// Parses an integer number from the stream.
+    ///
+    /// Unlike [`parse_number`], this method consumes an optional sign and a
+    /// run of ASCII digits directly, without going through `f64`. A fraction,
+    /// an exponent or a unit suffix is not consumed: parsing stops cleanly at
+    /// the first non-digit byte and leaves it on the stream, so it composes
+    /// inside list and path parsing.
+    ///
+    /// [`parse_number`]: Stream::parse_number
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidNumber` when no digits are present or the value
+    /// overflows an `i32`.
+    pub fn parse_integer(&mut self) -> Result<i32, Error> {
+        self.skip_spaces();
+
+        let start = self.pos();
+
+        if self.at_end() {
+            return Err(Error::InvalidNumber(self.calc_char_pos_at(start)));
+        }
+
+        self.parse_integer_impl()
+            .map_err(|_| Error::InvalidNumber(self.calc_char_pos_at(start)))
+    }
+
+    fn parse_integer_impl(&mut self) -> Result<i32, Error> {
+        let mut c = self.curr_byte()?;
+
+        let mut sign = 1i64;
+        if c.is_sign() {
+            if c == b'-' {
+                sign = -1;
+            }
+
+            self.advance(1);
+            c = self.curr_byte()?;
+        }
+
+        if !c.is_digit() {
+            return Err(Error::InvalidNumber(0));
+        }
+
+        let mut value: i64 = 0;
+        while let Ok(c) = self.curr_byte() {
+            if !c.is_digit() {
+                break;
+            }
+
+            value = value * 10 + i64::from(c - b'0');
+            if value * sign > i64::from(i32::MAX) || value * sign < i64::from(i32::MIN) {
+                return Err(Error::InvalidNumber(0));
+            }
+
+            self.advance(1);
+        }
+
+        Ok((value * sign) as i32)
+    }
+
+    /// Parses an integer from a list of integers.
+    pub fn parse_list_integer(&mut self) -> Result<i32, Error> {
+        if self.at_end() {
+            return Err(Error::UnexpectedEndOfStream);
+        }
+
+        let n = self.parse_integer()?;
+        self.skip_spaces();
+        self.parse_list_separator();
+        Ok(n)
+    }
+}
+
+/// A pull-based [`<list-of-integers>`] parser.
+///
+/// # Examples
+///
+/// ```
+/// use svgtypes::IntegerListParser;
+///
+/// let mut p = IntegerListParser::from("10, 20 -50");
+/// assert_eq!(p.next().unwrap().unwrap(), 10);
+/// assert_eq!(p.next().unwrap().unwrap(), 20);
+/// assert_eq!(p.next().unwrap().unwrap(), -50);
+/// assert_eq!(p.next().is_none(), true);
+/// ```
+///
+/// [`<list-of-integers>`]: https://www.w3.org/TR/SVG2/types.html#InterfaceSVGNumberList
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct IntegerListParser<'a>(Stream<'a>);
+
+impl<'a> From<&'a str> for IntegerListParser<'a> {
+    #[inline]
+    fn from(v: &'a str) -> Self {
+        IntegerListParser(Stream::from(v))
+    }
+}
+
+impl<'a> Iterator for IntegerListParser<'a> {
+    type Item = Result<i32, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.at_end() {
+            None
+        } else {
+            let v = self.0.parse_list_integer();
+            if v.is_err() {
+                self.0.jump_to_end();
+            }
+
+            Some(v)
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::Stream;
+
+    macro_rules! test_p {
+        ($name:ident, $text:expr, $result:expr) => (
+            #[test]
+            fn $name() {
+                let mut s = Stream::from($text);
+                assert_eq!(s.parse_integer().unwrap(), $result);
+            }
+        )
+    }
+
+    test_p!(parse_1, "0",    0);
+    test_p!(parse_2, "1",    1);
+    test_p!(parse_3, "-1",  -1);
+    test_p!(parse_4, " -1 ", -1);
+    test_p!(parse_5, "+10",  10);
+    test_p!(parse_6, "2147483647",  2147483647);
+    test_p!(parse_7, "-2147483648", -2147483648);
+
+    macro_rules! test_p_err {
+        ($name:ident, $text:expr) => (
+            #[test]
+            fn $name() {
+                let mut s = Stream::from($text);
+                assert!(s.parse_integer().is_err());
+            }
+        )
+    }
+
+    test_p_err!(parse_err_1, "q");
+    test_p_err!(parse_err_2, "");
+    test_p_err!(parse_err_3, ".");
+    test_p_err!(parse_err_4, "2147483648");
+    test_p_err!(parse_err_5, "-2147483649");
+
+    #[test]
+    fn parse_stops_at_dot() {
+        let mut s = Stream::from("1.5");
+        assert_eq!(s.parse_integer().unwrap(), 1);
+        assert_eq!(s.curr_byte().unwrap(), b'.');
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright 2024 the SVG Types Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::str::FromStr;
+
+use crate::{Error, Stream};
+
+/// A length unit.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LengthUnit {
+    None,
+    Em,
+    Ex,
+    Px,
+    In,
+    Cm,
+    Mm,
+    Pt,
+    Pc,
+    Percent,
+}
+
+/// An [SVG length](https://www.w3.org/TR/SVG2/types.html#InterfaceSVGLength).
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Length {
+    pub number: f64,
+    pub unit: LengthUnit,
+}
+
+impl Length {
+    /// Constructs a new length.
+    #[inline]
+    pub fn new(number: f64, unit: LengthUnit) -> Self {
+        Length { number, unit }
+    }
+
+    /// Constructs a new length with a `None` unit.
+    #[inline]
+    pub fn new_number(number: f64) -> Self {
+        Length {
+            number,
+            unit: LengthUnit::None,
+        }
+    }
+}
+
+impl Default for Length {
+    #[inline]
+    fn default() -> Self {
+        Length::new(0.0, LengthUnit::None)
+    }
+}
+
+impl FromStr for Length {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut s = Stream::from(text);
+        let l = s.parse_length()?;
+        s.skip_spaces();
+        if !s.at_end() {
+            return Err(Error::UnexpectedData(s.calc_char_pos()));
+        }
+
+        Ok(l)
+    }
+}
+
+impl<'a> Stream<'a> {
+    /// Parses length from the stream.
+    ///
+    /// This method calls [`parse_number`] and then matches a unit, if any.
+    ///
+    /// <https://www.w3.org/TR/SVG2/types.html#InterfaceSVGLength>
+    ///
+    /// [`parse_number`]: Stream::parse_number
+    ///
+    /// # Errors
+    ///
+    /// Returns only `InvalidNumber`. The [`FromStr`] impl additionally
+    /// returns `UnexpectedData` when trailing, non-whitespace data remains.
+    pub fn parse_length(&mut self) -> Result<Length, Error> {
+        let n = self.parse_number()?;
+
+        let unit = if self.starts_with(b"%") {
+            self.advance(1);
+            LengthUnit::Percent
+        } else if self.starts_with(b"em") {
+            self.advance(2);
+            LengthUnit::Em
+        } else if self.starts_with(b"ex") {
+            self.advance(2);
+            LengthUnit::Ex
+        } else if self.starts_with(b"px") {
+            self.advance(2);
+            LengthUnit::Px
+        } else if self.starts_with(b"in") {
+            self.advance(2);
+            LengthUnit::In
+        } else if self.starts_with(b"cm") {
+            self.advance(2);
+            LengthUnit::Cm
+        } else if self.starts_with(b"mm") {
+            self.advance(2);
+            LengthUnit::Mm
+        } else if self.starts_with(b"pt") {
+            self.advance(2);
+            LengthUnit::Pt
+        } else if self.starts_with(b"pc") {
+            self.advance(2);
+            LengthUnit::Pc
+        } else {
+            LengthUnit::None
+        };
+
+        Ok(Length::new(n, unit))
+    }
+
+    /// Parses length from a list of lengths.
+    pub fn parse_list_length(&mut self) -> Result<Length, Error> {
+        if self.at_end() {
+            return Err(Error::UnexpectedEndOfStream);
+        }
+
+        let l = self.parse_length()?;
+        self.skip_spaces();
+        self.parse_list_separator();
+        Ok(l)
+    }
+}
+
+/// A pull-based [`<list-of-lengths>`] parser.
+///
+/// # Examples
+///
+/// ```
+/// use svgtypes::{LengthListParser, Length, LengthUnit};
+///
+/// let mut p = LengthListParser::from("10px, 20% -50mm");
+/// assert_eq!(p.next().unwrap().unwrap(), Length::new(10.0, LengthUnit::Px));
+/// assert_eq!(p.next().unwrap().unwrap(), Length::new(20.0, LengthUnit::Percent));
+/// assert_eq!(p.next().unwrap().unwrap(), Length::new(-50.0, LengthUnit::Mm));
+/// assert_eq!(p.next().is_none(), true);
+/// ```
+///
+/// [`<list-of-lengths>`]: https://www.w3.org/TR/SVG2/types.html#InterfaceSVGLengthList
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct LengthListParser<'a>(Stream<'a>);
+
+impl<'a> From<&'a str> for LengthListParser<'a> {
+    #[inline]
+    fn from(v: &'a str) -> Self {
+        LengthListParser(Stream::from(v))
+    }
+}
+
+impl<'a> Iterator for LengthListParser<'a> {
+    type Item = Result<Length, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.at_end() {
+            None
+        } else {
+            let v = self.0.parse_list_length();
+            if v.is_err() {
+                self.0.jump_to_end();
+            }
+
+            Some(v)
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::Stream;
+    use super::*;
+
+    macro_rules! test_p {
+        ($name:ident, $text:expr, $number:expr, $unit:expr) => (
+            #[test]
+            fn $name() {
+                let mut s = Stream::from($text);
+                let length = s.parse_length().unwrap();
+                assert_eq!(length.number, $number);
+                assert_eq!(length.unit, $unit);
+            }
+        )
+    }
+
+    test_p!(parse_1,  "1",    1.0, LengthUnit::None);
+    test_p!(parse_2,  "1em",  1.0, LengthUnit::Em);
+    test_p!(parse_3,  "1ex",  1.0, LengthUnit::Ex);
+    test_p!(parse_4,  "1px",  1.0, LengthUnit::Px);
+    test_p!(parse_5,  "1in",  1.0, LengthUnit::In);
+    test_p!(parse_6,  "1cm",  1.0, LengthUnit::Cm);
+    test_p!(parse_7,  "1mm",  1.0, LengthUnit::Mm);
+    test_p!(parse_8,  "1pt",  1.0, LengthUnit::Pt);
+    test_p!(parse_9,  "1pc",  1.0, LengthUnit::Pc);
+    test_p!(parse_10, "1%",   1.0, LengthUnit::Percent);
+    test_p!(parse_11, "-1",  -1.0, LengthUnit::None);
+    test_p!(parse_12, " 1 ",  1.0, LengthUnit::None);
+
+    #[test]
+    fn from_str_1() {
+        assert_eq!("1px".parse::<Length>().unwrap(), Length::new(1.0, LengthUnit::Px));
+    }
+
+    #[test]
+    fn from_str_2() {
+        assert!("1px text".parse::<Length>().is_err());
+    }
+}
@@ -0,0 +1,15 @@
+// Copyright 2018 the SVG Types Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![warn(missing_docs)]
+
+mod error;
+mod integer;
+mod length;
+mod number;
+mod write;
+
+pub use crate::error::Error;
+pub use crate::integer::IntegerListParser;
+pub use crate::length::{Length, LengthListParser, LengthUnit};
+pub use crate::number::{write_numbers, Number, NumberListLength, NumberListParser, NumberParseMode};
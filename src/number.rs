@@ -1,14 +1,34 @@
 // Copyright 2018 the SVG Types Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use std::fmt;
 use std::str::FromStr;
 
+use crate::write::WriteBuffer;
 use crate::{ByteExt, Error, Stream};
 
 /// An [SVG number](https://www.w3.org/TR/SVG2/types.html#InterfaceSVGNumber).
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct Number(pub f64);
 
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut buf = Vec::new();
+        self.0.write_buf(&mut buf);
+        f.write_str(&String::from_utf8(buf).unwrap())
+    }
+}
+
+/// Writes a list of numbers as a compact, round-trip SVG token list.
+///
+/// Numbers are joined with a single space, except before a negative number,
+/// since `10-50` is valid SVG and doesn't need a separator.
+pub fn write_numbers(list: &[f64]) -> String {
+    let mut buf = Vec::new();
+    list.write_buf(&mut buf);
+    String::from_utf8(buf).unwrap()
+}
+
 impl std::str::FromStr for Number {
     type Err = Error;
 
@@ -36,6 +56,22 @@ impl<'a> Stream<'a> {
     ///
     /// Returns only `InvalidNumber`.
     pub fn parse_number(&mut self) -> Result<f64, Error> {
+        self.parse_number_with(NumberParseMode::Strict)
+    }
+
+    /// Parses number from the stream using an explicit parsing mode.
+    ///
+    /// In [`NumberParseMode::Lenient`] mode, a dangling `e`/`E` not followed
+    /// by a valid exponent terminates the number before the `e` instead of
+    /// raising an error, matching how browsers recover from malformed
+    /// path/transform data (`1e` parses as `1.0`, leaving `e` on the stream).
+    ///
+    /// <https://www.w3.org/TR/SVG2/types.html#InterfaceSVGNumber>
+    ///
+    /// # Errors
+    ///
+    /// Returns only `InvalidNumber`.
+    pub fn parse_number_with(&mut self, mode: NumberParseMode) -> Result<f64, Error> {
         // Strip off leading whitespaces.
         self.skip_spaces();
 
@@ -45,11 +81,11 @@ impl<'a> Stream<'a> {
             return Err(Error::InvalidNumber(self.calc_char_pos_at(start)));
         }
 
-        self.parse_number_impl()
+        self.parse_number_impl(mode)
             .map_err(|_| Error::InvalidNumber(self.calc_char_pos_at(start)))
     }
 
-    fn parse_number_impl(&mut self) -> Result<f64, Error> {
+    fn parse_number_impl(&mut self, mode: NumberParseMode) -> Result<f64, Error> {
         let start = self.pos();
 
         let mut c = self.curr_byte()?;
@@ -75,21 +111,41 @@ impl<'a> Stream<'a> {
 
         if let Ok(c) = self.curr_byte() {
             if matches!(c, b'e' | b'E') {
-                let c2 = self.next_byte()?;
-                // Check for `em`/`ex`.
-                if c2 != b'm' && c2 != b'x' {
-                    self.advance(1);
-
-                    match self.curr_byte()? {
-                        b'+' | b'-' => {
-                            self.advance(1);
-                            self.skip_digits();
-                        }
-                        b'0'..=b'9' => self.skip_digits(),
-                        _ => {
-                            return Err(Error::InvalidNumber(0));
+                // Remember where the possible exponent starts, so a
+                // malformed one can be rolled back to in lenient mode.
+                let exponent_start = *self;
+
+                match self.next_byte() {
+                    // `em`/`ex` — not an exponent.
+                    Ok(c2) if c2 == b'm' || c2 == b'x' => {}
+                    Ok(_) => {
+                        self.advance(1);
+
+                        let has_digits = match self.curr_byte() {
+                            Ok(sign @ (b'+' | b'-' | b'0'..=b'9')) => {
+                                if sign == b'+' || sign == b'-' {
+                                    self.advance(1);
+                                }
+
+                                let digits_start = self.pos();
+                                self.skip_digits();
+                                self.pos() != digits_start
+                            }
+                            _ => false,
+                        };
+
+                        if !has_digits {
+                            match mode {
+                                NumberParseMode::Lenient => *self = exponent_start,
+                                NumberParseMode::Strict => return Err(Error::InvalidNumber(0)),
+                            }
                         }
                     }
+                    // A lone trailing `e`/`E` with nothing after it.
+                    Err(e) => match mode {
+                        NumberParseMode::Lenient => {}
+                        NumberParseMode::Strict => return Err(e),
+                    },
                 }
             }
         }
@@ -109,15 +165,99 @@ impl<'a> Stream<'a> {
 
     /// Parses number from a list of numbers.
     pub fn parse_list_number(&mut self) -> Result<f64, Error> {
+        self.parse_list_number_with(NumberParseMode::Strict)
+    }
+
+    /// Parses number from a list of numbers using an explicit parsing mode.
+    pub fn parse_list_number_with(&mut self, mode: NumberParseMode) -> Result<f64, Error> {
         if self.at_end() {
             return Err(Error::UnexpectedEndOfStream);
         }
 
-        let n = self.parse_number()?;
+        let n = self.parse_number_with(mode)?;
         self.skip_spaces();
         self.parse_list_separator();
         Ok(n)
     }
+
+    /// Parses a list of numbers with an expected count from the stream.
+    ///
+    /// This is useful for attributes that require an exact or capped number
+    /// of values, like `viewBox` (exactly 4) or `matrix` (exactly 6).
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidNumberCount` when fewer numbers than required are
+    /// present, or when extra, non-whitespace data remains after the
+    /// expected count has been consumed.
+    pub fn parse_number_list_bounded(
+        &mut self,
+        len: NumberListLength,
+    ) -> Result<Vec<f64>, Error> {
+        let start = self.pos();
+
+        let max = match len {
+            NumberListLength::Exact(n) | NumberListLength::Maximum(n) => Some(n),
+            NumberListLength::Unbounded => None,
+        };
+
+        let mut list = Vec::new();
+        while !self.at_end() {
+            if let Some(max) = max {
+                if list.len() == max {
+                    break;
+                }
+            }
+
+            list.push(self.parse_list_number()?);
+        }
+
+        self.skip_spaces();
+
+        if let NumberListLength::Exact(n) = len {
+            if list.len() != n {
+                return Err(Error::InvalidNumberCount(self.calc_char_pos_at(start)));
+            }
+        }
+
+        if let Some(max) = max {
+            if list.len() >= max && !self.at_end() {
+                return Err(Error::InvalidNumberCount(self.calc_char_pos_at(start)));
+            }
+        }
+
+        Ok(list)
+    }
+}
+
+/// A number parsing mode.
+///
+/// See [`Stream::parse_number_with`] for details.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NumberParseMode {
+    /// Reject a malformed number, e.g. a dangling exponent like `1e`.
+    Strict,
+    /// Recover from a malformed exponent by treating it as the end of the
+    /// number, leaving the dangling `e`/`E` on the stream.
+    Lenient,
+}
+
+impl Default for NumberParseMode {
+    #[inline]
+    fn default() -> Self {
+        NumberParseMode::Strict
+    }
+}
+
+/// An expected [`Stream::parse_number_list_bounded`] length.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NumberListLength {
+    /// The list must contain exactly this many numbers and nothing else.
+    Exact(usize),
+    /// The list must contain at most this many numbers and nothing else.
+    Maximum(usize),
+    /// The list may contain any number of values.
+    Unbounded,
 }
 
 /// A pull-based [`<list-of-numbers>`] parser.
@@ -136,12 +276,21 @@ impl<'a> Stream<'a> {
 ///
 /// [`<list-of-numbers>`]: https://www.w3.org/TR/SVG2/types.html#InterfaceSVGNumberList
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub struct NumberListParser<'a>(Stream<'a>);
+pub struct NumberListParser<'a>(Stream<'a>, NumberParseMode);
 
 impl<'a> From<&'a str> for NumberListParser<'a> {
     #[inline]
     fn from(v: &'a str) -> Self {
-        NumberListParser(Stream::from(v))
+        NumberListParser(Stream::from(v), NumberParseMode::Strict)
+    }
+}
+
+impl<'a> NumberListParser<'a> {
+    /// Constructs a new parser that uses an explicit parsing mode, e.g.
+    /// [`NumberParseMode::Lenient`] to recover from malformed real-world SVGs.
+    #[inline]
+    pub fn with_mode(text: &'a str, mode: NumberParseMode) -> Self {
+        NumberListParser(Stream::from(text), mode)
     }
 }
 
@@ -152,7 +301,7 @@ impl<'a> Iterator for NumberListParser<'a> {
         if self.0.at_end() {
             None
         } else {
-            let v = self.0.parse_list_number();
+            let v = self.0.parse_list_number_with(self.1);
             if v.is_err() {
                 self.0.jump_to_end();
             }
@@ -166,6 +315,7 @@ impl<'a> Iterator for NumberListParser<'a> {
 #[cfg(test)]
 mod tests {
     use crate::Stream;
+    use super::{Number, NumberListLength, NumberParseMode};
 
     macro_rules! test_p {
         ($name:ident, $text:expr, $result:expr) => (
@@ -200,7 +350,8 @@ mod tests {
     test_p!(parse_21, "12345678901234567890", 12345678901234567000.0);
     test_p!(parse_22, "0.", 0.0);
     test_p!(parse_23, "1.3e-2", 0.013);
-    // test_number!(parse_24, "1e", 1.0); // TODO: this
+    // `1e` is rejected in `Strict` mode; see `lenient_*` tests below for the
+    // `NumberParseMode::Lenient` recovery.
 
     macro_rules! test_p_err {
         ($name:ident, $text:expr) => (
@@ -221,4 +372,120 @@ mod tests {
     test_p_err!(parse_err_6, ".");
     test_p_err!(parse_err_7, "99999999e99999999");
     test_p_err!(parse_err_8, "-99999999e99999999");
+
+    #[test]
+    fn parse_bounded_exact_1() {
+        let mut s = Stream::from("1 2 3 4");
+        assert_eq!(
+            s.parse_number_list_bounded(NumberListLength::Exact(4)).unwrap(),
+            vec![1.0, 2.0, 3.0, 4.0]
+        );
+    }
+
+    #[test]
+    fn parse_bounded_exact_2() {
+        let mut s = Stream::from("1 2 3");
+        assert!(s.parse_number_list_bounded(NumberListLength::Exact(4)).is_err());
+    }
+
+    #[test]
+    fn parse_bounded_exact_3() {
+        let mut s = Stream::from("1 2 3 4 5");
+        assert!(s.parse_number_list_bounded(NumberListLength::Exact(4)).is_err());
+    }
+
+    #[test]
+    fn parse_bounded_maximum_1() {
+        let mut s = Stream::from("1 2");
+        assert_eq!(
+            s.parse_number_list_bounded(NumberListLength::Maximum(2)).unwrap(),
+            vec![1.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn parse_bounded_maximum_2() {
+        let mut s = Stream::from("1");
+        assert_eq!(
+            s.parse_number_list_bounded(NumberListLength::Maximum(2)).unwrap(),
+            vec![1.0]
+        );
+    }
+
+    #[test]
+    fn parse_bounded_maximum_3() {
+        let mut s = Stream::from("1 2 3");
+        assert!(s.parse_number_list_bounded(NumberListLength::Maximum(2)).is_err());
+    }
+
+    #[test]
+    fn parse_bounded_unbounded_1() {
+        let mut s = Stream::from("1 2 3");
+        assert_eq!(
+            s.parse_number_list_bounded(NumberListLength::Unbounded).unwrap(),
+            vec![1.0, 2.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn parse_bounded_empty() {
+        let mut s = Stream::from("");
+        assert!(s.parse_number_list_bounded(NumberListLength::Exact(1)).is_err());
+    }
+
+    macro_rules! test_lenient {
+        ($name:ident, $text:expr, $result:expr, $rest_byte:expr) => (
+            #[test]
+            fn $name() {
+                let mut s = Stream::from($text);
+                assert_eq!(s.parse_number_with(NumberParseMode::Lenient).unwrap(), $result);
+                assert_eq!(s.curr_byte().ok(), $rest_byte);
+            }
+        )
+    }
+
+    test_lenient!(lenient_1, "1e",   1.0, Some(b'e'));
+    test_lenient!(lenient_2, "1e+",  1.0, Some(b'e'));
+    test_lenient!(lenient_3, "1e-",  1.0, Some(b'e'));
+    test_lenient!(lenient_4, "1eq",  1.0, Some(b'e'));
+    test_lenient!(lenient_5, "1e2",  100.0, None);
+    test_lenient!(lenient_6, "1em",  1.0, Some(b'e'));
+
+    #[test]
+    fn strict_still_rejects_dangling_exponent() {
+        let mut s = Stream::from("1e");
+        assert!(s.parse_number_with(NumberParseMode::Strict).is_err());
+    }
+
+    #[test]
+    fn display_1() {
+        assert_eq!(Number(0.5).to_string(), ".5");
+    }
+
+    #[test]
+    fn display_2() {
+        assert_eq!(Number(4.0).to_string(), "4");
+    }
+
+    #[test]
+    fn write_numbers_1() {
+        assert_eq!(super::write_numbers(&[10.0, -50.0]), "10-50");
+    }
+
+    #[test]
+    fn write_numbers_2() {
+        assert_eq!(super::write_numbers(&[10.0, 20.0, 30.0]), "10 20 30");
+    }
+
+    #[test]
+    fn lenient_list_parser() {
+        use super::NumberListParser;
+
+        // The dangling `e` is left on the stream after the first number,
+        // so the remaining, non-numeric `e` ends the list with an error.
+        let mut p = NumberListParser::with_mode("1e", NumberParseMode::Lenient);
+        assert_eq!(p.next().unwrap().unwrap(), 1.0);
+        assert!(p.next().unwrap().is_err());
+        assert!(p.next().is_none());
+    }
 }
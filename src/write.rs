@@ -0,0 +1,126 @@
+// Copyright 2024 the SVG Types Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// A trait for writing a value as a compact, round-trip SVG token.
+pub(crate) trait WriteBuffer {
+    /// Appends the value to the buffer.
+    fn write_buf(&self, buf: &mut Vec<u8>);
+}
+
+impl WriteBuffer for f64 {
+    fn write_buf(&self, buf: &mut Vec<u8>) {
+        // NaN/Infinity have no SVG number representation; `Stream::parse_number`
+        // already rejects them on the way in, so fall back to `0` here rather
+        // than emit a token that can't round-trip.
+        if !self.is_finite() {
+            buf.push(b'0');
+            return;
+        }
+
+        if *self < 0.0 {
+            buf.push(b'-');
+        }
+
+        let value = self.abs();
+
+        let mut s = format!("{}", value);
+
+        // Drop a trailing `.0`: `4.0` -> `4`.
+        if let Some(start) = s.strip_suffix(".0") {
+            s.truncate(start.len());
+        }
+
+        // Drop the leading zero of a fraction: `0.5` -> `.5`.
+        if let Some(rest) = s.strip_prefix("0.") {
+            s = format!(".{}", rest);
+        }
+
+        // Switch to exponent form only when it's strictly shorter.
+        let exp = format!("{:e}", value);
+        if exp.len() < s.len() {
+            s = exp;
+        }
+
+        buf.extend_from_slice(s.as_bytes());
+    }
+}
+
+impl WriteBuffer for [f64] {
+    fn write_buf(&self, buf: &mut Vec<u8>) {
+        for (i, n) in self.iter().enumerate() {
+            // `10-50` is valid SVG, so the separator before a negative
+            // number can be omitted.
+            if i != 0 && !(n.is_finite() && *n < 0.0) {
+                buf.push(b' ');
+            }
+
+            n.write_buf(buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WriteBuffer;
+
+    fn to_string(n: f64) -> String {
+        let mut buf = Vec::new();
+        n.write_buf(&mut buf);
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn write_1() {
+        assert_eq!(to_string(4.0), "4");
+    }
+
+    #[test]
+    fn write_2() {
+        assert_eq!(to_string(0.5), ".5");
+    }
+
+    #[test]
+    fn write_3() {
+        assert_eq!(to_string(-0.5), "-.5");
+    }
+
+    #[test]
+    fn write_4() {
+        assert_eq!(to_string(-4.0), "-4");
+    }
+
+    #[test]
+    fn write_nan() {
+        assert_eq!(to_string(f64::NAN), "0");
+    }
+
+    #[test]
+    fn write_infinity() {
+        assert_eq!(to_string(f64::INFINITY), "0");
+        assert_eq!(to_string(f64::NEG_INFINITY), "0");
+    }
+
+    #[test]
+    fn write_5() {
+        assert_eq!(to_string(0.0), "0");
+    }
+
+    #[test]
+    fn write_6() {
+        assert_eq!(to_string(100000000000.0), "1e11");
+    }
+
+    #[test]
+    fn write_list_1() {
+        let mut buf = Vec::new();
+        [10.0, -50.0].write_buf(&mut buf);
+        assert_eq!(String::from_utf8(buf).unwrap(), "10-50");
+    }
+
+    #[test]
+    fn write_list_2() {
+        let mut buf = Vec::new();
+        [10.0, 20.0, 30.0].write_buf(&mut buf);
+        assert_eq!(String::from_utf8(buf).unwrap(), "10 20 30");
+    }
+}